@@ -0,0 +1,28 @@
+use clap::Args as ClapArgs;
+
+#[derive(ClapArgs, Debug)]
+pub struct NewArgs {
+	/// Name of the new program.
+	pub name: String,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct BuildArgs {
+	/// Also emit a JSON IDL describing the program's accounts and
+	/// instructions, alongside the compiled program.
+	#[clap(long)]
+	pub idl: bool,
+	/// Where to write the generated IDL. Defaults to `idl.json` in the
+	/// program directory.
+	#[clap(long)]
+	pub idl_out: Option<String>,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct TestArgs {
+	/// Only run tests whose name contains this string.
+	pub filter: Option<String>,
+}
+
+#[derive(ClapArgs, Debug)]
+pub struct CleanArgs {}
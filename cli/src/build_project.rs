@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::args::BuildArgs;
+use crate::idl;
+
+pub fn build_project(args: BuildArgs) -> anyhow::Result<()> {
+	let status = Command::new("cargo").arg("build-sbf").status()?;
+
+	if !status.success() {
+		anyhow::bail!("cargo build-sbf failed");
+	}
+
+	if args.idl {
+		write_idl(&args)?;
+	}
+
+	Ok(())
+}
+
+fn write_idl(args: &BuildArgs) -> anyhow::Result<()> {
+	let project_dir = std::env::current_dir()?;
+	let manifest = cargo_toml::Manifest::from_path(project_dir.join("Cargo.toml"))?;
+	let program_name = manifest
+		.package
+		.map(|package| package.name)
+		.unwrap_or_else(|| "program".to_string());
+
+	let idl = idl::generate_idl(&program_name, &project_dir.join("src"))?;
+	let out_path = args
+		.idl_out
+		.clone()
+		.map(PathBuf::from)
+		.unwrap_or_else(|| project_dir.join("idl.json"));
+
+	std::fs::write(&out_path, serde_json::to_string_pretty(&idl)?)?;
+	println!("Wrote IDL to {}", out_path.display());
+
+	Ok(())
+}
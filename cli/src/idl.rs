@@ -0,0 +1,197 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Serialize;
+use sha2::Digest;
+use syn::Fields;
+use syn::Item;
+
+/// A machine-readable description of a program's accounts and instructions,
+/// generated from its source so TypeScript/Rust clients can decode accounts
+/// and build instructions without hand-maintaining a parallel schema.
+#[derive(Serialize, Debug, Default)]
+pub struct Idl {
+	pub name: String,
+	pub accounts: Vec<IdlAccount>,
+	pub instructions: Vec<IdlInstruction>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct IdlField {
+	pub name: String,
+	pub ty: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct IdlAccount {
+	pub name: String,
+	/// `sha256("account:" + name)[..8]`, the same convention
+	/// `derive(Discriminator)` bakes in at macro-expansion time — lets a
+	/// client tell which on-chain bytes correspond to this struct.
+	pub discriminator: [u8; 8],
+	pub fields: Vec<IdlField>,
+}
+
+/// `sha256("account:TypeName")[..8]`, matching `macros::derive_discriminator`.
+fn account_discriminator(name: &str) -> [u8; 8] {
+	let preimage = format!("account:{name}");
+	let digest = sha2::Sha256::digest(preimage.as_bytes());
+	digest[..8].try_into().unwrap()
+}
+
+#[derive(Serialize, Debug)]
+pub struct IdlInstruction {
+	pub name: String,
+	pub args: Vec<IdlField>,
+}
+
+/// Walk every `.rs` file under `src_dir`, and collect:
+/// - Structs that derive `Discriminator`, as accounts (their fields become
+///   the account's data layout). Structs that derive only `Pod` are skipped,
+///   and `#[repr(C)]` is not checked.
+/// - Variants of an enum named `Instruction`, as instructions (each
+///   variant's fields become its argument list).
+pub fn generate_idl(program_name: &str, src_dir: &Path) -> anyhow::Result<Idl> {
+	let mut idl = Idl {
+		name: program_name.to_string(),
+		..Idl::default()
+	};
+
+	for path in rust_files(src_dir)? {
+		let contents = std::fs::read_to_string(&path)?;
+		let file = syn::parse_file(&contents)?;
+
+		for item in file.items {
+			match item {
+				Item::Struct(item_struct) if derives_account(&item_struct.attrs) => {
+					let name = item_struct.ident.to_string();
+					idl.accounts.push(IdlAccount {
+						discriminator: account_discriminator(&name),
+						name,
+						fields: struct_fields(&item_struct.fields),
+					});
+				}
+				Item::Enum(item_enum) if item_enum.ident == "Instruction" => {
+					for variant in item_enum.variants {
+						idl.instructions.push(IdlInstruction {
+							name: variant.ident.to_string(),
+							args: struct_fields(&variant.fields),
+						});
+					}
+				}
+				_ => {}
+			}
+		}
+	}
+
+	Ok(idl)
+}
+
+/// A struct is an account only if it derives `Discriminator` — the IDL's
+/// discriminator is computed from the same `sha256("account:TypeName")`
+/// convention that derive bakes in, so a struct without it either isn't an
+/// account or uses a legacy/manual discriminator the IDL can't recompute.
+/// Bare `#[derive(Pod)]` is not enough on its own: plenty of embedded/
+/// zero-copy structs derive `Pod` without being top-level accounts.
+fn derives_account(attrs: &[syn::Attribute]) -> bool {
+	attrs.iter().any(|attr| {
+		attr.path().is_ident("derive")
+			&& attr
+				.parse_args_with(
+					syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+				)
+				.map(|derives| derives.iter().any(|path| path.is_ident("Discriminator")))
+				.unwrap_or(false)
+	})
+}
+
+fn struct_fields(fields: &Fields) -> Vec<IdlField> {
+	fields
+		.iter()
+		.enumerate()
+		.map(|(index, field)| {
+			let ty = &field.ty;
+			IdlField {
+				name: field
+					.ident
+					.as_ref()
+					.map(|ident| ident.to_string())
+					.unwrap_or_else(|| index.to_string()),
+				ty: quote::quote!(#ty).to_string(),
+			}
+		})
+		.collect()
+}
+
+fn rust_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+	let mut files = Vec::new();
+
+	for entry in std::fs::read_dir(dir)? {
+		let path = entry?.path();
+
+		if path.is_dir() {
+			files.extend(rust_files(&path)?);
+		} else if path.extension().is_some_and(|ext| ext == "rs") {
+			files.push(path);
+		}
+	}
+
+	Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn generate_idl_collects_accounts_and_instructions() {
+		let dir = std::env::temp_dir().join(format!(
+			"steel-idl-test-{}-{}",
+			std::process::id(),
+			std::time::SystemTime::now()
+				.duration_since(std::time::UNIX_EPOCH)
+				.unwrap()
+				.as_nanos()
+		));
+		std::fs::create_dir_all(&dir).unwrap();
+		std::fs::write(
+			dir.join("lib.rs"),
+			r#"
+				#[repr(C)]
+				#[derive(Clone, Copy, Discriminator)]
+				pub struct Counter {
+					pub count: u64,
+				}
+
+				#[repr(C)]
+				#[derive(Clone, Copy, Pod, Zeroable)]
+				pub struct CounterInner {
+					pub count: u64,
+				}
+
+				pub enum Instruction {
+					Initialize { owner: Pubkey },
+					Increment,
+				}
+			"#,
+		)
+		.unwrap();
+
+		let idl = generate_idl("counter", &dir).unwrap();
+		std::fs::remove_dir_all(&dir).unwrap();
+
+		assert_eq!(idl.name, "counter");
+		// `CounterInner` derives only `Pod`, not `Discriminator`, so it's an
+		// embedded/zero-copy struct rather than a top-level account and must
+		// not show up here.
+		assert_eq!(idl.accounts.len(), 1);
+		assert_eq!(idl.accounts[0].name, "Counter");
+		assert_eq!(idl.accounts[0].discriminator, account_discriminator("Counter"));
+		assert_eq!(idl.accounts[0].fields[0].name, "count");
+		assert_eq!(idl.instructions.len(), 2);
+		assert_eq!(idl.instructions[0].name, "Initialize");
+		assert_eq!(idl.instructions[0].args[0].name, "owner");
+		assert_eq!(idl.instructions[1].name, "Increment");
+		assert!(idl.instructions[1].args.is_empty());
+	}
+}
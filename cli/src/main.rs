@@ -2,6 +2,7 @@ mod args;
 mod build_project;
 mod clean_project;
 mod config;
+mod idl;
 mod new_project;
 mod test_project;
 mod utils;
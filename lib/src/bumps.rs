@@ -0,0 +1,21 @@
+/// A record of canonical bumps discovered while validating PDAs, keyed by
+/// account label. Handlers that validate a PDA can stash the bump found
+/// during validation here and reuse it later (e.g. for `invoke_signed`)
+/// instead of paying for a second `find_program_address` call.
+pub type Bumps = std::collections::BTreeMap<&'static str, u8>;
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bumps_record_and_lookup() {
+		let mut bumps = Bumps::new();
+		bumps.insert("vault", 253);
+		bumps.insert("mint", 254);
+
+		assert_eq!(bumps.get("vault"), Some(&253));
+		assert_eq!(bumps.get("mint"), Some(&254));
+		assert_eq!(bumps.get("missing"), None);
+	}
+}
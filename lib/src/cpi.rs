@@ -0,0 +1,142 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// A builder for cross-program invocations. Wraps an [`Instruction`] and the
+/// [`AccountInfo`]s it touches, and dispatches to `invoke` or `invoke_signed`
+/// depending on whether PDA signer seeds are provided.
+///
+/// ```ignore
+/// Cpi::new(transfer_ix, &[source, destination, authority])
+///     .with_signer_seeds(&[b"vault", &[bump]])
+///     .invoke()?;
+/// ```
+pub struct Cpi<'a, 'info> {
+	instruction: Instruction,
+	account_infos: Vec<AccountInfo<'info>>,
+	signer_seeds: Vec<&'a [&'a [u8]]>,
+}
+
+impl<'a, 'info> Cpi<'a, 'info> {
+	pub fn new(instruction: Instruction, account_infos: &[AccountInfo<'info>]) -> Self {
+		Self {
+			instruction,
+			account_infos: account_infos.to_vec(),
+			signer_seeds: Vec::new(),
+		}
+	}
+
+	/// Sign the invocation on behalf of a PDA owned by the calling program.
+	pub fn with_signer_seeds(mut self, seeds: &'a [&'a [u8]]) -> Self {
+		self.signer_seeds.push(seeds);
+		self
+	}
+
+	/// Dispatch the CPI. Uses `invoke_signed` if any PDA signer seeds were
+	/// provided via [`Cpi::with_signer_seeds`], otherwise plain `invoke`.
+	pub fn invoke(&self) -> Result<(), ProgramError> {
+		if self.signer_seeds.is_empty() {
+			invoke(&self.instruction, &self.account_infos)
+		} else {
+			invoke_signed(&self.instruction, &self.account_infos, &self.signer_seeds)
+		}
+	}
+}
+
+/// Context for a single CPI: the program being invoked plus the accounts it
+/// needs, separate from the instruction data itself so callers can reuse the
+/// same accounts across multiple instruction builders.
+pub struct CpiContext<'a, 'info> {
+	pub program_id: &'a Pubkey,
+	pub account_infos: &'a [AccountInfo<'info>],
+	pub signer_seeds: &'a [&'a [&'a [u8]]],
+}
+
+impl<'a, 'info> CpiContext<'a, 'info> {
+	pub fn new(program_id: &'a Pubkey, account_infos: &'a [AccountInfo<'info>]) -> Self {
+		Self {
+			program_id,
+			account_infos,
+			signer_seeds: &[],
+		}
+	}
+
+	/// Attach the PDA seeds this context should sign with.
+	pub fn with_signer_seeds(mut self, signer_seeds: &'a [&'a [&'a [u8]]]) -> Self {
+		self.signer_seeds = signer_seeds;
+		self
+	}
+
+	pub fn invoke(&self, instruction: Instruction) -> Result<(), ProgramError> {
+		if self.signer_seeds.is_empty() {
+			invoke(&instruction, self.account_infos)
+		} else {
+			invoke_signed(&instruction, self.account_infos, self.signer_seeds)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Mutex;
+
+	use solana_program::program_stubs::set_syscall_stubs;
+	use solana_program::program_stubs::SyscallStubs;
+
+	use super::*;
+
+	/// Records whether the last invocation it observed carried signer seeds,
+	/// instead of performing a real cross-program invocation.
+	struct RecordingStubs;
+
+	static SAW_SIGNER_SEEDS: Mutex<Option<bool>> = Mutex::new(None);
+
+	impl SyscallStubs for RecordingStubs {
+		fn sol_invoke_signed(
+			&self,
+			_instruction: &Instruction,
+			_account_infos: &[AccountInfo],
+			signers_seeds: &[&[&[u8]]],
+		) -> Result<(), ProgramError> {
+			*SAW_SIGNER_SEEDS.lock().unwrap() = Some(!signers_seeds.is_empty());
+			Ok(())
+		}
+	}
+
+	fn test_account_info<'info>(key: &'info Pubkey, owner: &'info Pubkey) -> AccountInfo<'info> {
+		let lamports: &'info mut u64 = Box::leak(Box::new(0u64));
+		let data: &'info mut [u8] = Box::leak(Box::new([]));
+		AccountInfo::new(key, false, true, lamports, data, owner, false, 0)
+	}
+
+	// Both cases share the process-global syscall stub and `SAW_SIGNER_SEEDS`,
+	// so they run sequentially in one test rather than as separate #[test]s —
+	// under cargo's default parallel execution, separate tests race on that
+	// shared global state and can flip each other's result.
+	#[test]
+	fn cpi_dispatches_invoke_vs_invoke_signed_based_on_signer_seeds() {
+		set_syscall_stubs(Box::new(RecordingStubs));
+
+		let key = Pubkey::new_unique();
+		let owner = Pubkey::new_unique();
+		let instruction = Instruction::new_with_bytes(owner, &[], vec![]);
+
+		let account_info = test_account_info(&key, &owner);
+		Cpi::new(instruction.clone(), &[account_info])
+			.invoke()
+			.unwrap();
+		assert_eq!(*SAW_SIGNER_SEEDS.lock().unwrap(), Some(false));
+
+		let account_info = test_account_info(&key, &owner);
+		let bump = [254u8];
+		let seeds: &[&[u8]] = &[b"vault", &bump];
+		Cpi::new(instruction, &[account_info])
+			.with_signer_seeds(seeds)
+			.invoke()
+			.unwrap();
+		assert_eq!(*SAW_SIGNER_SEEDS.lock().unwrap(), Some(true));
+	}
+}
@@ -0,0 +1,24 @@
+/// Extends a legacy single-byte discriminator (from before 8-byte
+/// discriminators were introduced) into the zero-padded 8-byte array the
+/// current [`crate::Discriminator`] trait expects.
+///
+/// Existing account types that still key off a hand-picked `u8` can migrate
+/// incrementally by implementing `discriminator` as
+/// `steel::legacy_discriminator(N)` instead of switching to `#[derive(Discriminator)]`
+/// right away; the padding guarantees the comparison in `try_from_bytes`
+/// still matches data written under the old single-byte layout.
+pub const fn legacy_discriminator(byte: u8) -> [u8; 8] {
+	[byte, 0, 0, 0, 0, 0, 0, 0]
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn pads_with_zeros() {
+		assert_eq!(legacy_discriminator(7), [7, 0, 0, 0, 0, 0, 0, 0]);
+		assert_eq!(legacy_discriminator(0), [0u8; 8]);
+		assert_eq!(legacy_discriminator(255), [255, 0, 0, 0, 0, 0, 0, 0]);
+	}
+}
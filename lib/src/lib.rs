@@ -0,0 +1,22 @@
+pub mod bumps;
+pub mod cpi;
+pub mod discriminator;
+pub mod loaders;
+pub mod traits;
+pub mod versioned;
+
+pub use bumps::*;
+pub use cpi::*;
+pub use discriminator::*;
+pub use traits::*;
+pub use versioned::*;
+
+pub use solana_program;
+
+#[cfg(feature = "logs")]
+pub use solana_program::msg;
+
+#[cfg(feature = "derive")]
+pub use steel_macros::Accounts;
+#[cfg(feature = "derive")]
+pub use steel_macros::Discriminator;
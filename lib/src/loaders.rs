@@ -2,14 +2,21 @@ use bytemuck::Pod;
 use solana_program::account_info::AccountInfo;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
 
 use crate::AccountDeserialize;
 use crate::AccountInfoValidation;
 use crate::AsAccount;
+use crate::Bumps;
 use crate::CloseAccount;
+use crate::CreateAccount;
 use crate::Discriminator;
 use crate::LamportTransfer;
 
+#[cfg(feature = "spl")]
+use crate::InitSplAccount;
+
 impl AccountInfoValidation for AccountInfo<'_> {
 	fn is_signer(&self) -> Result<&Self, ProgramError> {
 		if !self.is_signer {
@@ -64,13 +71,6 @@ impl AccountInfoValidation for AccountInfo<'_> {
 		let data = self.try_borrow_data()?;
 		let data_len = 8 + std::mem::size_of::<T>();
 
-		if data[0].ne(&T::discriminator()) {
-			#[cfg(feature = "logs")]
-			crate::msg!("address: {} has invalid discriminator", self.key);
-
-			return Err(ProgramError::InvalidAccountData);
-		}
-
 		if data.len() != data_len {
 			#[cfg(feature = "logs")]
 			crate::msg!(
@@ -81,6 +81,13 @@ impl AccountInfoValidation for AccountInfo<'_> {
 			return Err(ProgramError::AccountDataTooSmall);
 		}
 
+		if data[..8].ne(T::discriminator().as_slice()) {
+			#[cfg(feature = "logs")]
+			crate::msg!("address: {} has invalid discriminator", self.key);
+
+			return Err(ProgramError::InvalidAccountData);
+		}
+
 		Ok(self)
 	}
 
@@ -129,6 +136,27 @@ impl AccountInfoValidation for AccountInfo<'_> {
 		Ok(self)
 	}
 
+	fn has_seeds_record(
+		&self,
+		name: &'static str,
+		seeds: &[&[u8]],
+		program_id: &Pubkey,
+		bumps: &mut Bumps,
+	) -> Result<&Self, ProgramError> {
+		let (pda, bump) = Pubkey::find_program_address(seeds, program_id);
+
+		if pda.ne(self.key) {
+			#[cfg(feature = "logs")]
+			crate::msg!("address: {} is invalid, expected pda: {}", self.key, pda);
+
+			return Err(ProgramError::InvalidSeeds);
+		}
+
+		bumps.insert(name, bump);
+
+		Ok(self)
+	}
+
 	fn has_seeds_with_bump(
 		&self,
 		seeds: &[&[u8]],
@@ -230,3 +258,235 @@ impl<'a, 'info> CloseAccount<'a, 'info> for AccountInfo<'info> {
 		Ok(())
 	}
 }
+
+impl<'a, 'info> CreateAccount<'a, 'info> for AccountInfo<'info> {
+	fn create_account<T: Discriminator>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+	) -> Result<(), ProgramError> {
+		self.is_empty()?;
+
+		let space = 8 + std::mem::size_of::<T>();
+		let lamports = Rent::get()?.minimum_balance(space);
+
+		solana_program::program::invoke(
+			&solana_program::system_instruction::create_account(
+				payer.key,
+				self.key,
+				lamports,
+				space as u64,
+				program_id,
+			),
+			&[payer.clone(), self.clone(), system_program.clone()],
+		)?;
+
+		self.try_borrow_mut_data()?[..8].copy_from_slice(&T::discriminator());
+
+		Ok(())
+	}
+
+	fn create_pda<T: Discriminator>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+		seeds: &[&[u8]],
+		bump: u8,
+	) -> Result<(), ProgramError> {
+		self.is_empty()?;
+
+		let space = 8 + std::mem::size_of::<T>();
+		let lamports = Rent::get()?.minimum_balance(space);
+		let bump_seed = [bump];
+		let mut signer_seeds = seeds.to_vec();
+		signer_seeds.push(&bump_seed);
+
+		solana_program::program::invoke_signed(
+			&solana_program::system_instruction::create_account(
+				payer.key,
+				self.key,
+				lamports,
+				space as u64,
+				program_id,
+			),
+			&[payer.clone(), self.clone(), system_program.clone()],
+			&[signer_seeds.as_slice()],
+		)?;
+
+		self.try_borrow_mut_data()?[..8].copy_from_slice(&T::discriminator());
+
+		Ok(())
+	}
+}
+
+#[cfg(feature = "spl")]
+impl<'a, 'info> InitSplAccount<'a, 'info> for AccountInfo<'info> {
+	fn init_mint(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		token_program: &'a AccountInfo<'info>,
+		extensions: &[spl_token_2022::extension::ExtensionType],
+		decimals: u8,
+		authority: &Pubkey,
+		freeze_authority: Option<&Pubkey>,
+	) -> Result<(), ProgramError> {
+		self.is_empty()?;
+
+		let space = spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+			spl_token_2022::state::Mint,
+		>(extensions)
+		.map_err(|_| ProgramError::InvalidAccountData)?;
+		let lamports = Rent::get()?.minimum_balance(space);
+
+		solana_program::program::invoke(
+			&solana_program::system_instruction::create_account(
+				payer.key,
+				self.key,
+				lamports,
+				space as u64,
+				token_program.key,
+			),
+			&[payer.clone(), self.clone(), system_program.clone()],
+		)?;
+
+		solana_program::program::invoke(
+			&spl_token_2022::instruction::initialize_mint2(
+				token_program.key,
+				self.key,
+				authority,
+				freeze_authority,
+				decimals,
+			)?,
+			&[self.clone()],
+		)
+	}
+
+	fn init_token_account(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		token_program: &'a AccountInfo<'info>,
+		mint: &'a AccountInfo<'info>,
+		owner: &Pubkey,
+	) -> Result<(), ProgramError> {
+		self.is_empty()?;
+
+		let mint_data = mint.try_borrow_data()?;
+		let mint_state =
+			spl_token_2022::extension::StateWithExtensions::<spl_token_2022::state::Mint>::unpack(
+				&mint_data,
+			)?;
+		let account_extensions =
+			spl_token_2022::extension::ExtensionType::get_required_init_account_extensions(
+				&mint_state.get_extension_types()?,
+			);
+		let space = spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+			spl_token_2022::state::Account,
+		>(&account_extensions)
+		.map_err(|_| ProgramError::InvalidAccountData)?;
+		drop(mint_data);
+		let lamports = Rent::get()?.minimum_balance(space);
+
+		solana_program::program::invoke(
+			&solana_program::system_instruction::create_account(
+				payer.key,
+				self.key,
+				lamports,
+				space as u64,
+				token_program.key,
+			),
+			&[payer.clone(), self.clone(), system_program.clone()],
+		)?;
+
+		solana_program::program::invoke(
+			&spl_token_2022::instruction::initialize_account3(
+				token_program.key,
+				self.key,
+				mint.key,
+				owner,
+			)?,
+			&[self.clone(), mint.clone()],
+		)
+	}
+
+	fn init_associated_token_account(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		token_program: &'a AccountInfo<'info>,
+		associated_token_program: &'a AccountInfo<'info>,
+		wallet: &'a AccountInfo<'info>,
+		mint: &'a AccountInfo<'info>,
+	) -> Result<(), ProgramError> {
+		self.is_empty()?;
+
+		solana_program::program::invoke(
+			&spl_associated_token_account::instruction::create_associated_token_account(
+				payer.key,
+				wallet.key,
+				mint.key,
+				token_program.key,
+			),
+			&[
+				payer.clone(),
+				self.clone(),
+				wallet.clone(),
+				mint.clone(),
+				system_program.clone(),
+				token_program.clone(),
+				associated_token_program.clone(),
+			],
+		)
+	}
+}
+
+#[cfg(all(test, feature = "spl"))]
+mod spl_tests {
+	#[test]
+	fn init_mint_space_grows_with_extensions() {
+		let bare = spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+			spl_token_2022::state::Mint,
+		>(&[])
+		.unwrap();
+
+		let with_transfer_fee =
+			spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+				spl_token_2022::state::Mint,
+			>(&[spl_token_2022::extension::ExtensionType::TransferFeeConfig])
+			.unwrap();
+
+		assert!(with_transfer_fee > bare);
+	}
+
+	#[test]
+	fn init_token_account_maps_mint_extensions_to_account_extensions() {
+		// A mint's `TransferFeeConfig` extension requires accounts of that
+		// mint to reserve space for the distinct `TransferFeeAmount`
+		// extension, not another `TransferFeeConfig` — init_token_account
+		// must translate via `get_required_init_account_extensions` rather
+		// than reusing the mint's own extension list.
+		let account_extensions =
+			spl_token_2022::extension::ExtensionType::get_required_init_account_extensions(&[
+				spl_token_2022::extension::ExtensionType::TransferFeeConfig,
+			]);
+
+		assert_eq!(
+			account_extensions,
+			vec![spl_token_2022::extension::ExtensionType::TransferFeeAmount]
+		);
+
+		let bare = spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+			spl_token_2022::state::Account,
+		>(&[])
+		.unwrap();
+		let with_account_extensions = spl_token_2022::extension::ExtensionType::try_calculate_account_len::<
+			spl_token_2022::state::Account,
+		>(&account_extensions)
+		.unwrap();
+
+		assert!(with_account_extensions > bare);
+	}
+}
@@ -3,6 +3,8 @@ use solana_program::account_info::AccountInfo;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
+use crate::Bumps;
+
 pub trait AccountDeserialize {
 	fn try_from_bytes(data: &[u8]) -> Result<&Self, ProgramError>;
 	fn try_from_bytes_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError>;
@@ -13,7 +15,7 @@ where
 	T: Discriminator + Pod,
 {
 	fn try_from_bytes(data: &[u8]) -> Result<&Self, ProgramError> {
-		if Self::discriminator().ne(&data[0]) {
+		if data.len() < 8 || Self::discriminator().as_slice().ne(&data[..8]) {
 			return Err(solana_program::program_error::ProgramError::InvalidAccountData);
 		}
 		bytemuck::try_from_bytes::<Self>(&data[8..]).or(Err(
@@ -22,7 +24,7 @@ where
 	}
 
 	fn try_from_bytes_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
-		if Self::discriminator().ne(&data[0]) {
+		if data.len() < 8 || Self::discriminator().as_slice().ne(&data[..8]) {
 			return Err(solana_program::program_error::ProgramError::InvalidAccountData);
 		}
 		bytemuck::try_from_bytes_mut::<Self>(&mut data[8..]).or(Err(
@@ -48,7 +50,7 @@ where
 	T: Discriminator + Pod,
 {
 	fn try_header_from_bytes(data: &[u8]) -> Result<(&Self, &[u8]), ProgramError> {
-		if Self::discriminator().ne(&data[0]) {
+		if data.len() < 8 || Self::discriminator().as_slice().ne(&data[..8]) {
 			return Err(solana_program::program_error::ProgramError::InvalidAccountData);
 		}
 		let (prefix, remainder) = data[8..].split_at(std::mem::size_of::<T>());
@@ -120,6 +122,16 @@ pub trait AccountInfoValidation {
 	fn has_owner(&self, program_id: &Pubkey) -> Result<&Self, ProgramError>;
 	/// Check if the account has the seeds provided and uses the canonical bump.
 	fn has_seeds(&self, seeds: &[&[u8]], program_id: &Pubkey) -> Result<&Self, ProgramError>;
+	/// Check if the account has the seeds provided and uses the canonical
+	/// bump, recording the bump discovered in `bumps` under `name` so
+	/// callers don't have to call `find_program_address` a second time.
+	fn has_seeds_record(
+		&self,
+		name: &'static str,
+		seeds: &[&[u8]],
+		program_id: &Pubkey,
+		bumps: &mut Bumps,
+	) -> Result<&Self, ProgramError>;
 	/// Check if the account has the seeds and bump provided
 	fn has_seeds_with_bump(
 		&self,
@@ -137,8 +149,14 @@ pub trait AccountInfoValidation {
 	) -> Result<&Self, ProgramError>;
 }
 
+/// An 8-byte tag stored as the first 8 bytes of an account's data, used to
+/// identify which type the remaining bytes should be interpreted as. Derive
+/// this with `#[derive(Discriminator)]` to get a stable hash of
+/// `"account:TypeName"` computed at compile time, or implement it by hand
+/// (e.g. via [`crate::legacy_discriminator`]) for types migrating off the
+/// old single-byte scheme.
 pub trait Discriminator {
-	fn discriminator() -> u8;
+	fn discriminator() -> [u8; 8];
 }
 
 /// Performs:
@@ -173,6 +191,49 @@ pub trait AsSplAccount {
 	fn as_token_account(&self) -> Result<spl_token_2022::pod::PodAccount, ProgramError>;
 }
 
+#[cfg(feature = "spl")]
+pub trait InitSplAccount<'a, 'info> {
+	/// Allocate and initialize a new SPL mint, sized for the given
+	/// `spl_token_2022` extension types (pass `&[]` for a bare mint with no
+	/// extensions). The account is created and `InitializeMint2` is invoked
+	/// within this one call, so no extension-specific setup instruction
+	/// (e.g. `transfer_fee_config`) runs in between; only the space for the
+	/// listed extensions is reserved.
+	fn init_mint(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		token_program: &'a AccountInfo<'info>,
+		extensions: &[spl_token_2022::extension::ExtensionType],
+		decimals: u8,
+		authority: &Pubkey,
+		freeze_authority: Option<&Pubkey>,
+	) -> Result<(), ProgramError>;
+
+	/// Allocate and initialize a new SPL token account for `mint`, owned by
+	/// `owner`.
+	fn init_token_account(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		token_program: &'a AccountInfo<'info>,
+		mint: &'a AccountInfo<'info>,
+		owner: &Pubkey,
+	) -> Result<(), ProgramError>;
+
+	/// Create the associated token account for `wallet` and `mint` via a CPI
+	/// into the associated-token program.
+	fn init_associated_token_account(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		token_program: &'a AccountInfo<'info>,
+		associated_token_program: &'a AccountInfo<'info>,
+		wallet: &'a AccountInfo<'info>,
+		mint: &'a AccountInfo<'info>,
+	) -> Result<(), ProgramError>;
+}
+
 pub trait LamportTransfer<'a, 'info> {
 	fn send(&'a self, lamports: u64, to: &'a AccountInfo<'info>);
 	fn collect(&'a self, lamports: u64, from: &'a AccountInfo<'info>) -> Result<(), ProgramError>;
@@ -182,6 +243,30 @@ pub trait CloseAccount<'a, 'info> {
 	fn close(&'a self, to: &'a AccountInfo<'info>) -> Result<(), ProgramError>;
 }
 
+/// Performs the inverse of [`CloseAccount`]: allocates and funds a fresh
+/// account (or PDA) sized for `8 + size_of::<T>()` bytes and stamps its
+/// discriminator, so it immediately passes [`AccountInfoValidation::is_type`].
+pub trait CreateAccount<'a, 'info> {
+	/// Create a new account owned by `program_id`, funded by `payer`.
+	fn create_account<T: Discriminator>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+	) -> Result<(), ProgramError>;
+
+	/// Create a new PDA owned by `program_id`, funded by `payer`, signing
+	/// with the provided seeds and bump.
+	fn create_pda<T: Discriminator>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+		seeds: &[&[u8]],
+		bump: u8,
+	) -> Result<(), ProgramError>;
+}
+
 pub trait Loggable {
 	fn log(&self);
 	fn log_return(&self);
@@ -214,8 +299,8 @@ mod tests {
 	}
 
 	impl Discriminator for GenericallySizedTypeHeader {
-		fn discriminator() -> u8 {
-			0
+		fn discriminator() -> [u8; 8] {
+			crate::legacy_discriminator(0)
 		}
 	}
 
@@ -244,8 +329,8 @@ mod tests {
 	}
 
 	impl Discriminator for TestType {
-		fn discriminator() -> u8 {
-			7
+		fn discriminator() -> [u8; 8] {
+			crate::legacy_discriminator(7)
 		}
 	}
 
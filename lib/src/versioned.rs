@@ -0,0 +1,278 @@
+use bytemuck::Pod;
+use solana_program::account_info::AccountInfo;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+
+use crate::AccountInfoValidation;
+use crate::Discriminator;
+
+/// Layout offset of the version byte, immediately following the 8-byte
+/// discriminator and before the account body.
+pub const VERSION_OFFSET: usize = 8;
+
+/// An account type whose on-chain layout can change across deploys. The
+/// discriminator still identifies the *type*; the version byte that follows
+/// it identifies the *layout* of the body, so [`AsAccountVersioned::as_account_versioned`]
+/// can detect an account written under an older layout and upgrade it in
+/// place before handing back a typed reference.
+pub trait Versioned: Discriminator + Pod {
+	/// The current on-chain layout version. Bump this whenever `Self`'s
+	/// fields change shape.
+	fn current_version() -> u8;
+
+	/// Upgrade the body bytes of an account stored under `from_version` to
+	/// the layout of `from_version + 1`, returning the new body. Called once
+	/// per version between `from_version` and `current_version`, oldest
+	/// first, so a migration only ever has to know about its own successor;
+	/// the body returned by the last step must be sized for `Self`.
+	fn migrate(body: &[u8], from_version: u8) -> Result<Vec<u8>, ProgramError>;
+}
+
+/// Performs the same checks as [`crate::AsAccount`], but additionally
+/// upgrades the account in place if its stored version is older than
+/// `T::current_version()`.
+pub trait AsAccountVersioned {
+	fn as_account_versioned<T: Versioned>(&self, program_id: &Pubkey) -> Result<&T, ProgramError>;
+}
+
+impl AsAccountVersioned for AccountInfo<'_> {
+	fn as_account_versioned<T: Versioned>(&self, program_id: &Pubkey) -> Result<&T, ProgramError> {
+		self.has_owner(program_id)?;
+
+		let body_offset = VERSION_OFFSET + 1;
+
+		let stored_version = {
+			let data = self.try_borrow_data()?;
+
+			if data.len() < body_offset || data[..VERSION_OFFSET].ne(T::discriminator().as_slice()) {
+				return Err(ProgramError::InvalidAccountData);
+			}
+
+			data[VERSION_OFFSET]
+		};
+
+		if stored_version != T::current_version() {
+			self.migrate_to_current::<T>(stored_version)?;
+		}
+
+		let data = self.try_borrow_data()?;
+		if data.len() < body_offset + std::mem::size_of::<T>() {
+			return Err(ProgramError::InvalidAccountData);
+		}
+
+		unsafe {
+			bytemuck::try_from_bytes::<T>(std::slice::from_raw_parts(
+				data.as_ptr().add(body_offset),
+				std::mem::size_of::<T>(),
+			))
+			.or(Err(ProgramError::InvalidAccountData))
+		}
+	}
+}
+
+/// The versioned counterpart to [`crate::CreateAccount`]: allocates space
+/// for `discriminator(8) | version(1) | body` and stamps both the
+/// discriminator and `T::current_version()`, so the layout an account is
+/// created with always matches what [`AsAccountVersioned::as_account_versioned`]
+/// expects to read back. `CreateAccount::create_account` does not reserve
+/// the version byte, so it must not be used for `T: Versioned` types.
+pub trait CreateAccountVersioned<'a, 'info> {
+	fn create_account_versioned<T: Versioned>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+	) -> Result<(), ProgramError>;
+
+	fn create_pda_versioned<T: Versioned>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+		seeds: &[&[u8]],
+		bump: u8,
+	) -> Result<(), ProgramError>;
+}
+
+impl<'a, 'info> CreateAccountVersioned<'a, 'info> for AccountInfo<'info> {
+	fn create_account_versioned<T: Versioned>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+	) -> Result<(), ProgramError> {
+		self.is_empty()?;
+
+		let space = VERSION_OFFSET + 1 + std::mem::size_of::<T>();
+		let lamports = Rent::get()?.minimum_balance(space);
+
+		solana_program::program::invoke(
+			&solana_program::system_instruction::create_account(
+				payer.key,
+				self.key,
+				lamports,
+				space as u64,
+				program_id,
+			),
+			&[payer.clone(), self.clone(), system_program.clone()],
+		)?;
+
+		stamp_versioned::<T>(self)
+	}
+
+	fn create_pda_versioned<T: Versioned>(
+		&'a self,
+		payer: &'a AccountInfo<'info>,
+		system_program: &'a AccountInfo<'info>,
+		program_id: &Pubkey,
+		seeds: &[&[u8]],
+		bump: u8,
+	) -> Result<(), ProgramError> {
+		self.is_empty()?;
+
+		let space = VERSION_OFFSET + 1 + std::mem::size_of::<T>();
+		let lamports = Rent::get()?.minimum_balance(space);
+		let bump_seed = [bump];
+		let mut signer_seeds = seeds.to_vec();
+		signer_seeds.push(&bump_seed);
+
+		solana_program::program::invoke_signed(
+			&solana_program::system_instruction::create_account(
+				payer.key,
+				self.key,
+				lamports,
+				space as u64,
+				program_id,
+			),
+			&[payer.clone(), self.clone(), system_program.clone()],
+			&[signer_seeds.as_slice()],
+		)?;
+
+		stamp_versioned::<T>(self)
+	}
+}
+
+fn stamp_versioned<T: Versioned>(account_info: &AccountInfo) -> Result<(), ProgramError> {
+	let mut data = account_info.try_borrow_mut_data()?;
+	data[..VERSION_OFFSET].copy_from_slice(&T::discriminator());
+	data[VERSION_OFFSET] = T::current_version();
+	Ok(())
+}
+
+trait MigrateToCurrent {
+	fn migrate_to_current<T: Versioned>(&self, from_version: u8) -> Result<(), ProgramError>;
+}
+
+impl MigrateToCurrent for AccountInfo<'_> {
+	fn migrate_to_current<T: Versioned>(&self, from_version: u8) -> Result<(), ProgramError> {
+		if from_version > T::current_version() {
+			// A rolled-back program reading an account written by a newer
+			// version: there's no migration path backwards, so reject up
+			// front instead of spinning `migrate` until `version` wraps.
+			return Err(ProgramError::InvalidAccountData);
+		}
+
+		let body_offset = VERSION_OFFSET + 1;
+		let mut version = from_version;
+		let mut body = self.try_borrow_data()?[body_offset..].to_vec();
+
+		while version != T::current_version() {
+			body = T::migrate(&body, version)?;
+			version = version.checked_add(1).ok_or(ProgramError::InvalidAccountData)?;
+		}
+
+		let new_len = body_offset + std::mem::size_of::<T>();
+		self.realloc(new_len, false)?;
+
+		let mut data = self.try_borrow_mut_data()?;
+		data[..VERSION_OFFSET].copy_from_slice(&T::discriminator());
+		data[VERSION_OFFSET] = T::current_version();
+		data[body_offset..].copy_from_slice(&body);
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use bytemuck::Zeroable;
+
+	use super::*;
+
+	#[repr(C)]
+	#[derive(Copy, Clone, Zeroable, Pod)]
+	struct AccountV2 {
+		balance: u64,
+		bonus: u64,
+	}
+
+	impl Discriminator for AccountV2 {
+		fn discriminator() -> [u8; 8] {
+			crate::legacy_discriminator(9)
+		}
+	}
+
+	impl Versioned for AccountV2 {
+		fn current_version() -> u8 {
+			1
+		}
+
+		fn migrate(body: &[u8], from_version: u8) -> Result<Vec<u8>, ProgramError> {
+			assert_eq!(from_version, 0);
+			// Version 0's body was just a `u64` balance; version 1 adds a
+			// zeroed `bonus` field after it.
+			let balance = u64::from_le_bytes(body.try_into().unwrap());
+			let migrated = AccountV2 { balance, bonus: 0 };
+			Ok(bytemuck::bytes_of(&migrated).to_vec())
+		}
+	}
+
+	#[test]
+	fn migrate_upgrades_body_to_current_layout() {
+		let old_body = 42u64.to_le_bytes().to_vec();
+
+		let new_body = AccountV2::migrate(&old_body, 0).unwrap();
+		let migrated: &AccountV2 = bytemuck::from_bytes(&new_body);
+
+		assert_eq!(migrated.balance, 42);
+		assert_eq!(migrated.bonus, 0);
+		assert_eq!(new_body.len(), std::mem::size_of::<AccountV2>());
+	}
+
+	fn test_account_info<'info>(
+		key: &'info Pubkey,
+		owner: &'info Pubkey,
+		data: &'info mut [u8],
+	) -> AccountInfo<'info> {
+		let lamports: &'info mut u64 = Box::leak(Box::new(0u64));
+		AccountInfo::new(key, false, true, lamports, data, owner, false, 0)
+	}
+
+	#[test]
+	fn as_account_versioned_rejects_too_short_account_instead_of_panicking() {
+		let key = Pubkey::new_unique();
+		let owner = Pubkey::new_unique();
+		let data: &'static mut [u8] = Box::leak(Box::new([0u8; 3]));
+		let account_info = test_account_info(&key, &owner, data);
+
+		let result = account_info.as_account_versioned::<AccountV2>(&owner);
+
+		assert_eq!(result.unwrap_err(), ProgramError::InvalidAccountData);
+	}
+
+	#[test]
+	fn migrate_to_current_rejects_version_newer_than_current() {
+		let key = Pubkey::new_unique();
+		let owner = Pubkey::new_unique();
+		let data: &'static mut [u8] = Box::leak(Box::new(
+			[0u8; VERSION_OFFSET + 1 + std::mem::size_of::<AccountV2>()],
+		));
+		let account_info = test_account_info(&key, &owner, data);
+
+		let result = account_info.migrate_to_current::<AccountV2>(AccountV2::current_version() + 1);
+
+		assert_eq!(result.unwrap_err(), ProgramError::InvalidAccountData);
+	}
+}
@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::Expr;
+use syn::Field;
+use syn::Ident;
+use syn::Meta;
+use syn::Token;
+
+/// The parsed contents of a field's `#[account(...)]` attribute.
+#[derive(Default)]
+pub struct AccountAttr {
+	pub signer: bool,
+	pub is_mut: bool,
+	pub owner: Option<Expr>,
+	pub seeds: Option<Vec<Expr>>,
+	/// The field is itself a nested `#[derive(Accounts)]` group; validate it
+	/// by delegating to its own `try_accounts` rather than the single-field
+	/// validation chain.
+	pub nested: bool,
+}
+
+impl AccountAttr {
+	pub fn parse(field: &Field) -> syn::Result<Self> {
+		let mut attr = AccountAttr::default();
+
+		for field_attr in &field.attrs {
+			if !field_attr.path().is_ident("account") {
+				continue;
+			}
+
+			if matches!(field_attr.meta, Meta::Path(_)) {
+				attr.nested = true;
+				continue;
+			}
+
+			let items = field_attr
+				.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+
+			for item in items {
+				match item {
+					Meta::Path(path) if path.is_ident("signer") => attr.signer = true,
+					Meta::Path(path) if path.is_ident("mut") => attr.is_mut = true,
+					Meta::NameValue(kv) if kv.path.is_ident("owner") => {
+						attr.owner = Some(kv.value);
+					}
+					Meta::List(list) if list.path.is_ident("seeds") => {
+						let seeds = list
+							.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+						attr.seeds = Some(seeds.into_iter().collect());
+					}
+					Meta::NameValue(kv) if kv.path.is_ident("seeds") => {
+						let Expr::Array(array) = kv.value else {
+							return Err(syn::Error::new_spanned(
+								kv.value,
+								"seeds = ... must be an array expression, e.g. seeds = [b\"vault\"]",
+							));
+						};
+						attr.seeds = Some(array.elems.into_iter().collect());
+					}
+					other => {
+						return Err(syn::Error::new_spanned(other, "unsupported account constraint"));
+					}
+				}
+			}
+		}
+
+		Ok(attr)
+	}
+
+	/// Generate the statement that pulls this field's `AccountInfo` off the
+	/// iterator, runs its validation chain, and binds it to `field_name`.
+	pub fn to_binding(&self, field_name: &Ident, field_ty: &syn::Type) -> TokenStream2 {
+		if self.nested {
+			return quote! {
+				let #field_name = <#field_ty>::try_accounts_from_iter(program_id, accounts, bumps)?;
+			};
+		}
+
+		let mut checks = TokenStream2::new();
+
+		if self.signer {
+			checks.extend(quote!(.is_signer()?));
+		}
+
+		if self.is_mut {
+			checks.extend(quote!(.is_writable()?));
+		}
+
+		if let Some(owner) = &self.owner {
+			checks.extend(quote!(.has_owner(#owner)?));
+		}
+
+		let binding = if let Some(seeds) = &self.seeds {
+			let name_str = field_name.to_string();
+			quote! {
+				let #field_name = accounts.next().ok_or(solana_program::program_error::ProgramError::NotEnoughAccountKeys)?;
+				#field_name #checks;
+				#field_name.has_seeds_record(#name_str, &[#(#seeds),*], program_id, &mut *bumps)?;
+			}
+		} else {
+			quote! {
+				let #field_name = accounts.next().ok_or(solana_program::program_error::ProgramError::NotEnoughAccountKeys)?;
+				#field_name #checks;
+			}
+		};
+
+		binding
+	}
+}
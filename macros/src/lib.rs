@@ -0,0 +1,143 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use sha2::Digest;
+use sha2::Sha256;
+use syn::parse_macro_input;
+use syn::Data;
+use syn::DeriveInput;
+use syn::Fields;
+
+mod account_attr;
+
+use account_attr::AccountAttr;
+
+/// Derives a `try_accounts` constructor for a struct of account fields,
+/// replacing the hand-rolled sequence of `AccountInfoValidation` calls every
+/// instruction handler would otherwise write out. Each field is bound to its
+/// validated `AccountInfo`; no `AsAccount` conversion to a typed account is
+/// performed.
+///
+/// ```ignore
+/// #[derive(Accounts)]
+/// pub struct Deposit<'a, 'info> {
+///     #[account(signer, mut)]
+///     pub payer: &'a AccountInfo<'info>,
+///     #[account(mut, seeds = [b"vault", payer.key.as_ref()], owner = program_id)]
+///     pub vault: &'a AccountInfo<'info>,
+/// }
+/// ```
+///
+/// Expands to an associated `Deposit::try_accounts(program_id, accounts) ->
+/// Result<(Self, Bumps), ProgramError>` that splits `accounts` one field at a
+/// time, runs the matching validation chain, and records any discovered PDA
+/// bumps. A field marked with a bare `#[account]` attribute (its type must
+/// itself derive `Accounts`) is treated as a nested group and validated by
+/// delegating to its own `try_accounts_from_iter`, which is how shared
+/// account groups get composed.
+#[proc_macro_derive(Accounts, attributes(account))]
+pub fn derive_accounts(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let Data::Struct(data) = &input.data else {
+		return syn::Error::new_spanned(&input, "Accounts can only be derived for structs")
+			.to_compile_error()
+			.into();
+	};
+
+	let Fields::Named(fields) = &data.fields else {
+		return syn::Error::new_spanned(&input, "Accounts requires named fields")
+			.to_compile_error()
+			.into();
+	};
+
+	let mut field_bindings = Vec::<TokenStream2>::new();
+	let mut field_names = Vec::<TokenStream2>::new();
+
+	for field in &fields.named {
+		let field_name = field.ident.as_ref().unwrap();
+		let attr = match AccountAttr::parse(field) {
+			Ok(attr) => attr,
+			Err(error) => return error.to_compile_error().into(),
+		};
+
+		field_names.push(quote!(#field_name));
+		field_bindings.push(attr.to_binding(field_name, &field.ty));
+	}
+
+	// Use lifetime names that can't collide with lifetimes the target struct
+	// already declares (e.g. the `'a, 'info` in this macro's own doc
+	// example) — reusing the struct's own generics here would be E0496.
+	let expanded = quote! {
+		impl #impl_generics #name #ty_generics #where_clause {
+			/// Splits `accounts` and validates each field in declaration
+			/// order, returning the typed struct and any PDA bumps
+			/// discovered along the way.
+			pub fn try_accounts<'__steel_a, '__steel_info>(
+				program_id: &solana_program::pubkey::Pubkey,
+				accounts: &'__steel_a [solana_program::account_info::AccountInfo<'__steel_info>],
+			) -> Result<(Self, steel::Bumps), solana_program::program_error::ProgramError>
+			where
+				Self: Sized,
+			{
+				let mut accounts = accounts.iter();
+				let mut bumps = steel::Bumps::new();
+				let value = Self::try_accounts_from_iter(program_id, &mut accounts, &mut bumps)?;
+				Ok((value, bumps))
+			}
+
+			/// Validates fields against a shared account iterator, allowing
+			/// one `#[derive(Accounts)]` struct to be embedded inside
+			/// another and consume from the same underlying slice.
+			pub fn try_accounts_from_iter<'__steel_a, '__steel_info>(
+				program_id: &solana_program::pubkey::Pubkey,
+				accounts: &mut std::slice::Iter<'__steel_a, solana_program::account_info::AccountInfo<'__steel_info>>,
+				bumps: &mut steel::Bumps,
+			) -> Result<Self, solana_program::program_error::ProgramError>
+			where
+				Self: Sized,
+			{
+				#(#field_bindings)*
+
+				Ok(Self {
+					#(#field_names),*
+				})
+			}
+		}
+	};
+
+	expanded.into()
+}
+
+/// Derives [`steel::Discriminator`] by hashing `"account:TypeName"` with
+/// SHA-256 and taking the first 8 bytes, following the same account
+/// discriminator convention as other Solana program frameworks. The hash is
+/// computed at macro-expansion time, so the result is a plain `[u8; 8]`
+/// literal baked into the binary, not a runtime hash.
+///
+/// Types migrating from the legacy single-byte discriminator should keep a
+/// manual `impl Discriminator` built on [`steel::legacy_discriminator`]
+/// instead of switching to this derive, since the hashed value won't match
+/// data already written on-chain under the old byte.
+#[proc_macro_derive(Discriminator)]
+pub fn derive_discriminator(input: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+	let preimage = format!("account:{name}");
+	let digest = sha2::Sha256::digest(preimage.as_bytes());
+	let bytes: [u8; 8] = digest[..8].try_into().unwrap();
+
+	let expanded = quote! {
+		impl #impl_generics steel::Discriminator for #name #ty_generics #where_clause {
+			fn discriminator() -> [u8; 8] {
+				[#(#bytes),*]
+			}
+		}
+	};
+
+	expanded.into()
+}
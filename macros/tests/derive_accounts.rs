@@ -0,0 +1,9 @@
+#[test]
+fn expands_for_structs_with_their_own_lifetimes() {
+	// This is the exact shape shown in `derive_accounts`'s own doc example.
+	// It previously failed to compile with E0496 because the generated
+	// `try_accounts`/`try_accounts_from_iter` methods re-declared `'a, 'info`
+	// on top of the struct's own lifetimes of the same name.
+	let cases = trybuild::TestCases::new();
+	cases.pass("tests/ui/derive_accounts_pass.rs");
+}
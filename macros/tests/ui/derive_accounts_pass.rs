@@ -0,0 +1,12 @@
+use solana_program::account_info::AccountInfo;
+use steel::Accounts;
+
+#[derive(Accounts)]
+pub struct Deposit<'a, 'info> {
+	#[account(signer, mut)]
+	pub payer: &'a AccountInfo<'info>,
+	#[account(mut, seeds = [b"vault", payer.key.as_ref()], owner = program_id)]
+	pub vault: &'a AccountInfo<'info>,
+}
+
+fn main() {}